@@ -1,10 +1,49 @@
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine as _;
 use hdrhistogram::iterators::{HistogramIterator, PickyIterator};
+use hdrhistogram::serialization::interval_log::IntervalLogIterator;
+use hdrhistogram::serialization::interval_log::IntervalLogWriterBuilder;
+use hdrhistogram::serialization::interval_log::LogEntry;
+use hdrhistogram::serialization::{Deserializer, V2DeflateSerializer};
+use hdrhistogram::sync::{Recorder, SyncHistogram};
 use hdrhistogram::Histogram;
 use std::fs::File;
-use std::io::{self, BufRead, Write};
+use std::io::{self, BufRead, Read, Write};
 use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use structopt::StructOpt;
 
+/// Output layout for the info block and percentile lines.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    /// The existing fixed human-readable layout.
+    Text,
+    /// Summary fields plus one percentile row per line, as CSV.
+    Csv,
+    /// Summary fields plus one percentile object per line, as JSON.
+    Json,
+    /// The canonical HdrHistogram percentile-distribution columns,
+    /// densely sampled over logarithmically increasing percentile ticks.
+    Plot,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(Format::Text),
+            "csv" => Ok(Format::Csv),
+            "json" => Ok(Format::Json),
+            "plot" => Ok(Format::Plot),
+            other => Err(format!(
+                "Unknown --format '{}': expected one of text, csv, json, plot",
+                other
+            )),
+        }
+    }
+}
+
 #[derive(Debug, StructOpt)]
 #[structopt(name = "qhist", about = "Simple historgraphic information")]
 struct Opt {
@@ -42,6 +81,23 @@ struct Opt {
     #[structopt(short, long)]
     resolution: Option<u64>,
 
+    /// Geometric growth factor for percentile bucket display.
+    ///
+    /// When supplied the iteration method to generate buckets is
+    /// logarithmic stepping via `hist.iter_log`, starting at `--log-first`
+    /// and multiplying by this base each step (e.g. first=1, base=2 gives
+    /// buckets at 1, 2, 4, 8, ...). Ignored when `--resolution` is also
+    /// given, since `--resolution` takes precedence. Interoperates with `--lower`/`--upper`/
+    /// `--max-lines` and the bar chart exactly like linear and recorded
+    /// iteration do, and gives a compact, readable distribution for
+    /// latency/size data that spans many orders of magnitude.
+    #[structopt(long)]
+    log_base: Option<f64>,
+
+    /// Initial bucket width for `--log-base` iteration.
+    #[structopt(long, default_value = "1")]
+    log_first: u64,
+
     /// Do not print simple info block
     #[structopt(long)]
     no_info: bool,
@@ -65,15 +121,101 @@ struct Opt {
     #[structopt(short, long, default_value = "100")]
     bar_length: f64,
 
-    /// Number of decimal places of to keep for floating point input. Will garble integer input. 
-    /// 
+    /// Draw bars with eighth-block Unicode glyphs instead of `-`
+    ///
+    /// Rather than truncating each bar to a whole number of `-` characters,
+    /// this renders the fractional remainder as one of the eighth-block
+    /// glyphs `▁▂▃▄▅▆▇█`, and guarantees that any bucket with a nonzero
+    /// count renders at least the thinnest `▁` mark. This gives much
+    /// higher-resolution bars in the same column width, at the cost of
+    /// needing a Unicode-aware terminal/font. The default `-` chart is
+    /// left untouched so scripts that scrape it don't break.
+    #[structopt(long)]
+    unicode: bool,
+
+    /// Number of decimal places of to keep for floating point input. Will garble integer input.
+    ///
     /// This is used to convert the input from floating point into an integer to be operated on.
-    /// Then used to convert back to a floating point for output. An input of `1.13` with `-s 2` 
+    /// Then used to convert back to a floating point for output. An input of `1.13` with `-s 2`
     /// will be converted to `1.13 * 10^2 = 113` and processed. When output it will be reconverted
     /// to `113 * 10^2 = 1.13`. This is because the underlying library for creating the histogram
     /// does not suppor floating point values.
     #[structopt(short, long)]
     sig_figs: Option<f64>,
+
+    /// Path to append the resulting histogram to, in HdrHistogram's
+    /// interval-log format.
+    ///
+    /// The histogram is serialized with the compressed V2 encoding, the
+    /// same representation the wider HdrHistogram ecosystem's readers and
+    /// plotters consume, and written as a single interval-log entry behind
+    /// a start-timestamp header. Running `qhist` again with the same
+    /// `--save` path appends another entry rather than overwriting, so a
+    /// file accumulates one entry per run.
+    #[structopt(long, parse(from_os_str))]
+    save: Option<PathBuf>,
+
+    /// Path to a previously `--save`d interval-log file to merge in.
+    ///
+    /// May be given multiple times. Every entry found across all given
+    /// files is decoded and `add`ed into the histogram built from this
+    /// run's input before any statistics are computed, so e.g.
+    /// `qhist --load a.hlog --load b.hlog` reports on the union of all
+    /// three sources.
+    #[structopt(long, parse(from_os_str))]
+    load: Vec<PathBuf>,
+
+    /// Ingest `--input` across N worker threads
+    ///
+    /// Instead of the single-threaded read-then-record loop, splits the
+    /// file into N line-aligned chunks and has each worker thread parse
+    /// its chunk and record directly into a lock-free `Recorder` backed by
+    /// a shared `SyncHistogram`, with no synchronization between workers.
+    /// The main thread then `refresh()`es the `SyncHistogram` to fold all
+    /// writes together before reporting. Only applies to `--input`; STDIN
+    /// is always read single-threaded since it cannot be chunked ahead of
+    /// time.
+    #[structopt(long)]
+    threads: Option<usize>,
+
+    /// Number of significant value digits to retain (1-5)
+    ///
+    /// Controls the precision/memory tradeoff HdrHistogram uses to bound
+    /// relative quantization error. When `--min`/`--max` are not given the
+    /// histogram still autosizes from zero with this many significant
+    /// digits; when they are given this is also the `sigfig` passed to
+    /// `Histogram::new_with_bounds`.
+    #[structopt(long, default_value = "3")]
+    precision: u8,
+
+    /// Lowest value trackable by the histogram
+    ///
+    /// Must be given together with `--max`, switching construction from
+    /// the autosizing `Histogram::new` to `Histogram::new_with_bounds`.
+    /// Values recorded outside `[--min, --max]` are not added to the
+    /// histogram; they instead increment a drop counter reported in the
+    /// info block rather than panicking.
+    #[structopt(long)]
+    min: Option<u64>,
+
+    /// Highest value trackable by the histogram
+    ///
+    /// Must be given together with `--min`. See `--min` for behavior.
+    #[structopt(long)]
+    max: Option<u64>,
+
+    /// Output format: text, csv, json, or plot
+    ///
+    /// `csv`/`json` emit the summary fields (samples, min, max, mean,
+    /// stdev, outlier thresholds) plus one row/object per percentile line,
+    /// honoring `--sig-figs`, `--lower`, `--upper`, and `--max-lines`
+    /// exactly as `text` does. `plot` emits the canonical HdrHistogram
+    /// percentile-distribution columns (`Value`, `Percentile`,
+    /// `TotalCount`, `1/(1-Percentile)`) over logarithmically increasing
+    /// percentile ticks, ready to paste into standard HdrHistogram
+    /// percentile plotters.
+    #[structopt(long, default_value = "text")]
+    format: Format,
 }
 
 struct App {
@@ -84,8 +226,14 @@ struct App {
     min_count: u64,
     max_lines: usize,
     no_info: bool,
+    no_percentiles: bool,
     bar_length: f64,
-    sig_figs: Option<f64>
+    unicode: bool,
+    sig_figs: Option<f64>,
+    precision: u8,
+    bounds: Option<(u64, u64)>,
+    drop_count: u64,
+    format: Format,
 }
 
 fn main() -> Result<(), std::io::Error> {
@@ -96,6 +244,32 @@ fn main() -> Result<(), std::io::Error> {
     if args.lower > args.upper {
         panic!("Lower percentile bound is greater than upper percentile bound");
     }
+    if !(1..=5).contains(&args.precision) {
+        panic!("--precision must be between 1 and 5");
+    }
+    if args.min.is_some() != args.max.is_some() {
+        panic!("--min and --max must be given together");
+    }
+    if let (Some(min), Some(max)) = (args.min, args.max) {
+        if min < 1 {
+            panic!("--min must be at least 1");
+        }
+        if max < 2 * min {
+            panic!("--max must be at least twice --min");
+        }
+    }
+    if let Some(base) = args.log_base {
+        if base <= 1. {
+            panic!("--log-base must be greater than 1");
+        }
+    }
+    if args.log_base.is_some() && args.log_first == 0 {
+        panic!("--log-first must be greater than 0");
+    }
+    let bounds = match (args.min, args.max) {
+        (Some(min), Some(max)) => Some((min, max)),
+        _ => None,
+    };
 
     // Barchart related argument dependencies.
     let no_bars = match args.no_percentiles {
@@ -115,56 +289,105 @@ fn main() -> Result<(), std::io::Error> {
         min_count: u64::MAX, 
         max_lines: args.max_lines, 
         no_info: args.no_info,
-        bar_length: bar_length, 
-        sig_figs: args.sig_figs 
+        no_percentiles: args.no_percentiles,
+        bar_length: bar_length,
+        unicode: args.unicode,
+        sig_figs: args.sig_figs,
+        precision: args.precision,
+        bounds,
+        drop_count: 0,
+        format: args.format,
     };
 
-    // Read in data
-    let lines: Vec<u64>;
-    if args.input == None {
-        let stdin = std::io::stdin();
-        let stdin = stdin.lock();
-        lines = read_data_from(stdin, &app);
-    } else {
-        let file: File = File::open(args.input.unwrap()).expect("file not found");
-        let file = io::BufReader::new(file);
-        lines = read_data_from(file, &app);
-    }
-
-    // Populate histogram
-    let mut hist = Histogram::<u64>::new(3).expect("Unable to create histogram");
+    // Read in data and populate the histogram
+    let mut hist = match (&args.input, args.threads) {
+        (Some(path), Some(threads)) if threads > 1 => parallel_ingest(path, threads, &mut app),
+        _ => {
+            let lines: Vec<u64>;
+            if args.input == None {
+                let stdin = std::io::stdin();
+                let stdin = stdin.lock();
+                lines = read_data_from(stdin, &app);
+            } else {
+                let file: File = File::open(args.input.unwrap()).expect("file not found");
+                let file = io::BufReader::new(file);
+                lines = read_data_from(file, &app);
+            }
 
-    for val in lines.iter() {
-        hist.record(*val)
-            .expect("Value added to histogram is out of range");
-        if hist.count_at(*val) > app.max_count {
-            app.max_count = hist.count_at(*val);
-        } else if hist.count_at(*val) < app.min_count {
-            app.min_count = hist.count_at(*val);
+            let mut hist = new_histogram(&app);
+            for val in lines.iter() {
+                if let Some((low, high)) = app.bounds {
+                    if *val < low || *val > high {
+                        app.drop_count += 1;
+                        continue;
+                    }
+                }
+                match hist.record(*val) {
+                    Ok(()) => {
+                        if hist.count_at(*val) > app.max_count {
+                            app.max_count = hist.count_at(*val);
+                        } else if hist.count_at(*val) < app.min_count {
+                            app.min_count = hist.count_at(*val);
+                        }
+                    }
+                    Err(_) => panic!("Value added to histogram is out of range"),
+                }
+            }
+            hist
         }
+    };
+
+    // Merge in any previously saved histograms.
+    if !args.load.is_empty() {
+        let loaded = load_histograms_from(&args.load);
+        hist.add(&loaded).expect("Unable to merge loaded histogram");
+    }
+
+    // Persist the resulting histogram for a future run to `--load`.
+    if let Some(save) = &args.save {
+        save_histogram_to(save, &hist).expect("Unable to save histogram");
     }
 
     // Print out the information
     let stdout = std::io::stdout();
     let mut stdout = stdout.lock();
 
-    if !args.no_info {
-        write_info_to(&mut stdout, &hist, app.sig_figs)?;
-    }
-
-    if !args.no_percentiles {
-        let percentiles = match args.resolution {
-            Some(resolution) => construct_percentiles(
-                &mut hist.iter_linear(resolution), &app),
-            None => construct_percentiles(
-                &mut hist.iter_recorded(), &app),
-        };
+    match app.format {
+        Format::Plot => write_plot_to(&mut stdout, &hist, &app)?,
+        Format::Csv | Format::Json => {
+            let rows = match (args.resolution, args.log_base) {
+                (Some(resolution), _) => construct_percentile_rows(&mut hist.iter_linear(resolution), &app),
+                (None, Some(base)) => construct_percentile_rows(&mut hist.iter_log(args.log_first, base), &app),
+                (None, None) => construct_percentile_rows(&mut hist.iter_recorded(), &app),
+            };
+            match app.format {
+                Format::Csv => write_csv_to(&mut stdout, &hist, &rows, &app)?,
+                Format::Json => write_json_to(&mut stdout, &hist, &rows, &app)?,
+                Format::Text | Format::Plot => unreachable!(),
+            }
+        }
+        Format::Text => {
+            if !args.no_info {
+                write_info_to(&mut stdout, &hist, &app)?;
+            }
 
-        write_percentiles_to(&mut stdout,
-            &percentiles,
-            app.max_lines,
-            app.no_info,
-        )?;
+            if !args.no_percentiles {
+                let percentiles = match (args.resolution, args.log_base) {
+                    (Some(resolution), _) => construct_percentiles(
+                        &mut hist.iter_linear(resolution), &app),
+                    (None, Some(base)) => construct_percentiles(
+                        &mut hist.iter_log(args.log_first, base), &app),
+                    (None, None) => construct_percentiles(
+                        &mut hist.iter_recorded(), &app),
+                };
+
+                write_percentiles_to(&mut stdout,
+                    &percentiles,
+                    app.max_lines,
+                    app.no_info,
+                )?;
+            }
+        }
     }
 
     Ok(())
@@ -174,44 +397,205 @@ fn main() -> Result<(), std::io::Error> {
 fn read_data_from<R: BufRead>(reader: R, app: &App) -> Vec<u64> {
     let lines: Vec<u64> = reader
         .lines()
-        .map(|line| {
-            let l = line.unwrap();
-            let l: Vec<&str> = l.split_ascii_whitespace().collect();
-            if l.len() <= app.column {
-                panic!(
-                    "Error! Given column does not exist in data for line:\n---\n{0}\n----",
-                    l.clone()[0]
-                );
-            }
-            match app.sig_figs {
-                Some(s) => {
-                    // We have requested some number of significant figures, s, be maintained.
-                    // This also assumes floating point input, c, was given.
-                    // So the converted value c = (l[column] * 10 ^ s) as u64
-                    let a: f64 = l[app.column].to_owned().parse::<f64>().expect(
-                        format!(
-                            "Value ({0:#?}) at column {1} was not parsable to a float!",
-                            l[app.column], app.column
-                        )
-                        .as_ref()
-                    ) as f64;
-                    (a * f64::powf(10., s)) as u64
-                },
-                None => {
-                    l[app.column].to_owned().parse::<u64>().expect(
-                        format!(
-                            "Value ({0:#?}) at column {1} was not parsable to an integer!",
-                            l[app.column], app.column
-                        )
-                        .as_ref(),
-                    )
-                }
-            }
-        })
+        .map(|line| parse_value_from_line(&line.unwrap(), app))
         .collect();
     lines
 }
 
+/// Parses the `app.column`'th whitespace-delimited field of `line` into a
+/// `u64`, applying `app.sig_figs` floating-point scaling if requested.
+/// Shared by the single-threaded `read_data_from` path and the
+/// `--threads` parallel ingestion workers so both parse identically.
+fn parse_value_from_line(line: &str, app: &App) -> u64 {
+    let l: Vec<&str> = line.split_ascii_whitespace().collect();
+    if l.len() <= app.column {
+        panic!(
+            "Error! Given column does not exist in data for line:\n---\n{0}\n----",
+            l.clone()[0]
+        );
+    }
+    match app.sig_figs {
+        Some(s) => {
+            // We have requested some number of significant figures, s, be maintained.
+            // This also assumes floating point input, c, was given.
+            // So the converted value c = (l[column] * 10 ^ s) as u64
+            let a: f64 = l[app.column].to_owned().parse::<f64>().expect(
+                format!(
+                    "Value ({0:#?}) at column {1} was not parsable to a float!",
+                    l[app.column], app.column
+                )
+                .as_ref()
+            ) as f64;
+            (a * f64::powf(10., s)) as u64
+        },
+        None => {
+            l[app.column].to_owned().parse::<u64>().expect(
+                format!(
+                    "Value ({0:#?}) at column {1} was not parsable to an integer!",
+                    l[app.column], app.column
+                )
+                .as_ref(),
+            )
+        }
+    }
+}
+
+/// Ingests `path` across `threads` worker threads using HdrHistogram's
+/// concurrent recording support.
+///
+/// The file is memory-mapped and split into `threads` line-aligned byte
+/// ranges. Each worker thread owns a lock-free `Recorder` (from
+/// `SyncHistogram::recorder()`) and parses+records its range directly,
+/// with no synchronization between workers and no intermediate `Vec<u64>`.
+/// Once every worker has joined, the main thread calls `refresh()` to fold
+/// all of their writes into the final histogram.
+fn parallel_ingest(path: &PathBuf, threads: usize, app: &mut App) -> Histogram<u64> {
+    let file = File::open(path).expect("file not found");
+    let mapping = unsafe { memmap2::Mmap::map(&file).expect("Unable to memory-map input file") };
+    let contents = std::str::from_utf8(&mapping).expect("Input file was not valid UTF-8");
+
+    let mut sync_hist: SyncHistogram<u64> = new_histogram(app).into();
+    let bounds = app.bounds;
+    let dropped = std::sync::atomic::AtomicU64::new(0);
+
+    std::thread::scope(|scope| {
+        for chunk in line_aligned_chunks(contents, threads) {
+            let mut recorder: Recorder<u64> = sync_hist.recorder();
+            let app = &*app;
+            let dropped = &dropped;
+            scope.spawn(move || {
+                for line in chunk.lines() {
+                    let val = parse_value_from_line(line, app);
+                    if let Some((low, high)) = bounds {
+                        if val < low || val > high {
+                            dropped.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                            continue;
+                        }
+                    }
+                    match recorder.record(val) {
+                        Ok(()) => {}
+                        Err(_) => panic!("Value added to histogram is out of range"),
+                    }
+                }
+            });
+        }
+    });
+
+    app.drop_count += dropped.load(std::sync::atomic::Ordering::Relaxed);
+    sync_hist.refresh();
+
+    for v in sync_hist.iter_recorded() {
+        let count = v.count_since_last_iteration();
+        if count > app.max_count {
+            app.max_count = count;
+        } else if count < app.min_count {
+            app.min_count = count;
+        }
+    }
+
+    // `SyncHistogram` derefs to `Histogram`; clone the refreshed snapshot
+    // out so the rest of `main` can treat both ingestion paths the same.
+    (*sync_hist).clone()
+}
+
+/// Splits `contents` into `n` roughly equal byte ranges, each snapped
+/// forward to the next line boundary so no worker ever sees a line cut in
+/// half.
+fn line_aligned_chunks(contents: &str, n: usize) -> Vec<&str> {
+    let len = contents.len();
+    let target = len.div_ceil(n);
+
+    let mut chunks = Vec::with_capacity(n);
+    let mut start = 0;
+    while start < len {
+        let mut end = (start + target).min(len);
+        if end < len {
+            // Search the raw bytes rather than `contents[end..]`: `end` can
+            // land in the middle of a multi-byte UTF-8 character, and
+            // slicing a `str` at a non-char-boundary panics.
+            end += contents.as_bytes()[end..]
+                .iter()
+                .position(|&b| b == b'\n')
+                .map(|i| i + 1)
+                .unwrap_or(len - end);
+        }
+        chunks.push(&contents[start..end]);
+        start = end;
+    }
+    chunks
+}
+
+/// Appends `hist` to `path` as a single interval-log entry, creating the
+/// file if it does not already exist. Every call writes a fresh start-time
+/// header and legend line ahead of its entry, so a file appended to over
+/// several runs holds one header per entry.
+fn save_histogram_to(path: &PathBuf, hist: &Histogram<u64>) -> io::Result<()> {
+    let now = SystemTime::now();
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+
+    let mut serializer = V2DeflateSerializer::new();
+    let mut log_writer = IntervalLogWriterBuilder::new()
+        .with_start_time(now)
+        .begin_log_with(&mut file, &mut serializer)
+        .expect("Unable to begin interval log");
+
+    log_writer
+        .write_histogram(
+            hist,
+            now.duration_since(UNIX_EPOCH)
+                .expect("System time is before the Unix epoch"),
+            Duration::from_secs(0),
+            None,
+        )
+        .expect("Unable to write histogram to interval log");
+
+    Ok(())
+}
+
+/// Decodes every interval-log entry found across `paths` and folds them
+/// together into a single histogram via repeated `Histogram::add`.
+fn load_histograms_from(paths: &[PathBuf]) -> Histogram<u64> {
+    let mut merged = Histogram::<u64>::new(3).expect("Unable to create histogram");
+
+    for path in paths {
+        let mut contents = Vec::new();
+        File::open(path)
+            .expect("file not found")
+            .read_to_end(&mut contents)
+            .expect("Unable to read interval log file");
+
+        let mut deserializer = Deserializer::new();
+        for entry in IntervalLogIterator::new(&contents) {
+            let entry = entry.expect("Unable to parse interval log entry");
+            if let LogEntry::Interval(ilh) = entry {
+                let encoded = BASE64_STANDARD
+                    .decode(ilh.encoded_histogram())
+                    .expect("Unable to base64-decode interval log histogram");
+                let loaded: Histogram<u64> = deserializer
+                    .deserialize(&mut io::Cursor::new(&encoded))
+                    .expect("Unable to deserialize interval log histogram");
+                merged.add(&loaded).expect("Unable to merge loaded histogram");
+            }
+        }
+    }
+
+    merged
+}
+
+/// Builds the histogram to record into, honoring `--precision` and, when
+/// given, `--min`/`--max`.
+fn new_histogram(app: &App) -> Histogram<u64> {
+    match app.bounds {
+        Some((low, high)) => Histogram::<u64>::new_with_bounds(low, high, app.precision)
+            .expect("Unable to create histogram"),
+        None => Histogram::<u64>::new(app.precision).expect("Unable to create histogram"),
+    }
+}
+
 fn scale_per_sig_figs(value: f64, sig_figs: Option<f64>) -> f64 {
     match sig_figs {
         Some(s) => {
@@ -223,7 +607,8 @@ fn scale_per_sig_figs(value: f64, sig_figs: Option<f64>) -> f64 {
 }
 
 /// Prints simple histographic information to STDOUT
-fn write_info_to<W: Write>(writer: &mut W, hist: &Histogram<u64>, sig_figs: Option<f64>) -> Result<(), std::io::Error> {
+fn write_info_to<W: Write>(writer: &mut W, hist: &Histogram<u64>, app: &App) -> Result<(), std::io::Error> {
+    let sig_figs = app.sig_figs;
 
     writer.write_all(
         format!(
@@ -262,6 +647,13 @@ fn write_info_to<W: Write>(writer: &mut W, hist: &Histogram<u64>, sig_figs: Opti
             .as_ref(),
         )?;
     }
+
+    writer.write_all(format!("Precision: {0: >9}\n", app.precision).as_ref())?;
+    if let Some((low, high)) = app.bounds {
+        writer.write_all(format!("Bounds:   {0: >10} - {1}\n", low, high).as_ref())?;
+        writer.write_all(format!("Dropped:  {0: >10}\n", app.drop_count).as_ref())?;
+    }
+
     Ok(())
 }
 
@@ -295,7 +687,12 @@ fn construct_percentiles<I: PickyIterator<u64>>(
                     None => v.value_iterated_to() as f64,
                 },
                 v.count_since_last_iteration(),
-                bar_string(v.count_since_last_iteration(), app.max_count, app.min_count, app.bar_length)
+                // A zero bar length means bars are off (`--no-bars`), so the
+                // unicode path is skipped to avoid its minimum thin mark.
+                match app.unicode && app.bar_length > 0. {
+                    true => bar_string_unicode(v.count_since_last_iteration(), app.max_count, app.min_count, app.bar_length),
+                    false => bar_string(v.count_since_last_iteration(), app.max_count, app.min_count, app.bar_length),
+                }
             ));
         }
     }
@@ -303,6 +700,27 @@ fn construct_percentiles<I: PickyIterator<u64>>(
     out
 }
 
+/// Like `construct_percentiles`, but returns the raw `(percentile, value,
+/// count)` triples instead of a pre-rendered line, for the structured
+/// `csv`/`json` output formats.
+fn construct_percentile_rows<I: PickyIterator<u64>>(
+    hist: &mut HistogramIterator<u64, I>,
+    app: &App,
+) -> Vec<(f64, f64, u64)> {
+    let mut out: Vec<(f64, f64, u64)> = Vec::new();
+    for v in hist {
+        if app.lower as f64 <= v.percentile()
+            && v.count_since_last_iteration() != 0
+            && v.percentile() <= app.upper as f64
+        {
+            let value = scale_per_sig_figs(v.value_iterated_to() as f64, app.sig_figs);
+            out.push((v.percentile(), value, v.count_since_last_iteration()));
+        }
+    }
+    out.reverse();
+    out
+}
+
 /// Generate the scaled bar for the bucket represented by `val`
 fn bar_string(val: u64, max: u64, min: u64, max_length: f64) -> String {
     let scaling = get_scaled(val as f64, max as f64, min as f64);
@@ -310,6 +728,37 @@ fn bar_string(val: u64, max: u64, min: u64, max_length: f64) -> String {
     bar
 }
 
+/// The eighth-block glyphs used to render sub-character bar fractions,
+/// from thinnest to a full block.
+const EIGHTH_BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Generate the scaled bar for the bucket represented by `val`, using
+/// eighth-block Unicode glyphs so a fractional bar length is never rounded
+/// away to nothing. Any `val` greater than zero renders at least the
+/// thinnest `▁` mark.
+fn bar_string_unicode(val: u64, max: u64, min: u64, max_length: f64) -> String {
+    let scaling = get_scaled(val as f64, max as f64, min as f64);
+    let f = scaling * max_length;
+
+    let full_blocks = f.floor() as usize;
+    let remainder = f - f.floor();
+    let eighth = ((remainder * 8.) as usize).min(EIGHTH_BLOCKS.len() - 1);
+
+    let mut bar: String = EIGHTH_BLOCKS[EIGHTH_BLOCKS.len() - 1].to_string().repeat(full_blocks);
+    if val > 0 {
+        if remainder > 0. {
+            bar.push(EIGHTH_BLOCKS[eighth]);
+        } else if full_blocks == 0 {
+            // The minimum-count bucket scales to exactly zero blocks, and
+            // when every bucket has the same count the scaling is NaN; give
+            // it the thinnest mark rather than an empty bar, same as every
+            // other bucket with a nonzero count.
+            bar.push(EIGHTH_BLOCKS[0]);
+        }
+    }
+    bar
+}
+
 #[inline]
 fn get_scaled(val: f64, max: f64, min: f64) -> f64 {
     (val - min) / (max - min)
@@ -336,3 +785,160 @@ fn write_percentiles_to<W: Write>(
 
     Ok(())
 }
+
+/// Writes the summary fields as a CSV header/row, followed by one
+/// `percentile,value,count` row per entry in `rows`.
+fn write_csv_to<W: Write>(
+    writer: &mut W,
+    hist: &Histogram<u64>,
+    rows: &[(f64, f64, u64)],
+    app: &App,
+) -> io::Result<()> {
+    let sig_figs = app.sig_figs;
+
+    if !app.no_info {
+        let outliers_above = hist.mean() + 3. * hist.stdev();
+        let outliers_below = hist.mean() - 3. * hist.stdev();
+
+        writer.write_all(b"samples,max,min,mean,stdev,outlier_above,outlier_below\n")?;
+        writer.write_all(
+            format!(
+                "{0},{1},{2},{3},{4},{5},{6}\n",
+                hist.len(),
+                scale_per_sig_figs(hist.highest_equivalent(hist.value_at_percentile(100.)) as f64, sig_figs),
+                scale_per_sig_figs(hist.lowest_equivalent(hist.value_at_percentile(0.)) as f64, sig_figs),
+                scale_per_sig_figs(hist.mean(), sig_figs),
+                scale_per_sig_figs(hist.stdev(), sig_figs),
+                match outliers_above <= hist.max() as f64 {
+                    true => scale_per_sig_figs(outliers_above, sig_figs).to_string(),
+                    false => String::new(),
+                },
+                match outliers_below >= hist.min() as f64 {
+                    true => scale_per_sig_figs(outliers_below, sig_figs).to_string(),
+                    false => String::new(),
+                },
+            )
+            .as_ref(),
+        )?;
+    }
+
+    if !app.no_percentiles {
+        writer.write_all(b"percentile,value,count\n")?;
+        let line_count = rows.len().min(app.max_lines);
+        for (percentile, value, count) in &rows[0..line_count] {
+            writer.write_all(format!("{0},{1},{2}\n", percentile, value, count).as_ref())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the summary fields and `rows` as a single JSON object.
+fn write_json_to<W: Write>(
+    writer: &mut W,
+    hist: &Histogram<u64>,
+    rows: &[(f64, f64, u64)],
+    app: &App,
+) -> io::Result<()> {
+    let sig_figs = app.sig_figs;
+
+    writer.write_all(b"{\n")?;
+
+    if !app.no_info {
+        let outliers_above = hist.mean() + 3. * hist.stdev();
+        let outliers_below = hist.mean() - 3. * hist.stdev();
+
+        writer.write_all(format!("  \"samples\": {0},\n", hist.len()).as_ref())?;
+        writer.write_all(
+            format!(
+                "  \"max\": {0},\n",
+                scale_per_sig_figs(hist.highest_equivalent(hist.value_at_percentile(100.)) as f64, sig_figs)
+            )
+            .as_ref(),
+        )?;
+        writer.write_all(
+            format!(
+                "  \"min\": {0},\n",
+                scale_per_sig_figs(hist.lowest_equivalent(hist.value_at_percentile(0.)) as f64, sig_figs)
+            )
+            .as_ref(),
+        )?;
+        writer.write_all(format!("  \"mean\": {0},\n", scale_per_sig_figs(hist.mean(), sig_figs)).as_ref())?;
+        writer.write_all(format!("  \"stdev\": {0},\n", scale_per_sig_figs(hist.stdev(), sig_figs)).as_ref())?;
+
+        match outliers_above <= hist.max() as f64 {
+            true => writer.write_all(format!("  \"outlier_above\": {0},\n", scale_per_sig_figs(outliers_above, sig_figs)).as_ref())?,
+            false => writer.write_all(b"  \"outlier_above\": null,\n")?,
+        };
+        match outliers_below >= hist.min() as f64 {
+            true => writer.write_all(format!("  \"outlier_below\": {0},\n", scale_per_sig_figs(outliers_below, sig_figs)).as_ref())?,
+            false => writer.write_all(b"  \"outlier_below\": null,\n")?,
+        };
+    }
+
+    writer.write_all(b"  \"percentiles\": [\n")?;
+    if !app.no_percentiles {
+        let line_count = rows.len().min(app.max_lines);
+        for (i, (percentile, value, count)) in rows[0..line_count].iter().enumerate() {
+            let comma = if i + 1 == line_count { "" } else { "," };
+            writer.write_all(
+                format!(
+                    "    {{ \"percentile\": {0}, \"value\": {1}, \"count\": {2} }}{3}\n",
+                    percentile, value, count, comma
+                )
+                .as_ref(),
+            )?;
+        }
+    }
+    writer.write_all(b"  ]\n}\n")?;
+
+    Ok(())
+}
+
+/// Generates percentile ticks from `lower` to `upper` that grow
+/// logarithmically denser as they approach 100, by geometrically shrinking
+/// the remaining distance to 100 at each step. This densely samples the
+/// tail the way HdrHistogram's own percentile-distribution output does.
+fn log_percentile_ticks(lower: f64, upper: f64) -> Vec<f64> {
+    const SHRINK: f64 = 0.9;
+
+    let mut ticks = vec![lower];
+    let mut remaining = 100. - lower;
+    let floor = (100. - upper).max(1e-9);
+
+    while remaining > floor {
+        remaining *= SHRINK;
+        let percentile = 100. - remaining;
+        if percentile >= upper {
+            break;
+        }
+        ticks.push(percentile);
+    }
+    ticks.push(upper);
+    ticks
+}
+
+/// Writes the canonical HdrHistogram percentile-distribution columns —
+/// `Value`, `Percentile`, `TotalCount`, `1/(1-Percentile)` — over
+/// logarithmically increasing percentile ticks between `--lower` and
+/// `--upper`, suitable for pasting into standard HdrHistogram percentile
+/// plotters. This output is the percentile distribution and nothing else,
+/// so `--no-info`/`--no-percentiles` don't apply to it.
+fn write_plot_to<W: Write>(writer: &mut W, hist: &Histogram<u64>, app: &App) -> io::Result<()> {
+    writer.write_all(b"Value,Percentile,TotalCount,1/(1-Percentile)\n")?;
+
+    for percentile in log_percentile_ticks(app.lower as f64, app.upper as f64) {
+        let raw_value = hist.value_at_percentile(percentile);
+        let value = scale_per_sig_figs(raw_value as f64, app.sig_figs);
+        let total_count = hist.count_between(0, raw_value);
+        let inverse = match percentile >= 100. {
+            true => f64::INFINITY,
+            false => 1. / (1. - percentile / 100.),
+        };
+        writer.write_all(
+            format!("{0},{1},{2},{3}\n", value, percentile / 100., total_count, inverse).as_ref(),
+        )?;
+    }
+
+    Ok(())
+}